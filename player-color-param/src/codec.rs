@@ -0,0 +1,65 @@
+//! Generic read/write plumbing shared by `nucc_binary` `.bin` param tables.
+//!
+//! [`PlayerColorParam`](crate::PlayerColorParam) is the only table implemented so far, but the
+//! relative-pointer and NUL-terminated string conventions here are common to the format, so
+//! new tables can implement [`FromReader`]/[`ToWriter`] without duplicating them. The
+//! version/entry-count header has no helper here; each table's impl reads and writes it itself.
+
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::mem::size_of;
+
+/// A type that can be parsed from one of these `.bin` param tables.
+pub trait FromReader: Sized {
+    fn from_reader<R: Read + Seek>(reader: &mut R) -> io::Result<Self>;
+}
+
+/// A type that can be serialized back into one of these `.bin` param tables.
+pub trait ToWriter {
+    fn to_writer<W: Write + Seek>(&self, writer: &mut W) -> io::Result<()>;
+}
+
+/// A reader that can also seek, as a single object-safe trait so version-specific entry
+/// codecs can be stored as `fn(&mut dyn ReadSeek) -> ...` pointers in one lookup table
+/// instead of each being generic over its own reader type parameter.
+pub(crate) trait ReadSeek: Read + Seek {}
+impl<T: Read + Seek + ?Sized> ReadSeek for T {}
+
+/// Reads a NUL-terminated string.
+pub(crate) fn read_cstring(reader: &mut dyn Read) -> io::Result<String> {
+    let mut bytes = Vec::new();
+    loop {
+        let byte = reader.read_u8()?;
+        if byte == 0 {
+            break;
+        }
+        bytes.push(byte);
+    }
+    String::from_utf8(bytes)
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+}
+
+/// Reads one of this format's relative pointers: a `u64` measured from the position
+/// immediately after the pointer field itself, rather than from the start of the file.
+pub(crate) fn read_relative_offset(reader: &mut dyn Read) -> io::Result<u64> {
+    Ok(reader.read_u64::<LittleEndian>()? - size_of::<u64>() as u64)
+}
+
+/// Writes one of this format's relative pointers (see [`read_relative_offset`]).
+pub(crate) fn write_relative_offset(writer: &mut dyn Write, distance: u64) -> io::Result<()> {
+    writer.write_u64::<LittleEndian>(distance + size_of::<u64>() as u64)
+}
+
+/// Seeks past a relative pointer already read via [`read_relative_offset`] to run `read`
+/// at the position it points to, then restores the reader to where it started.
+pub(crate) fn at_relative_offset<T>(
+    reader: &mut dyn ReadSeek,
+    distance: u64,
+    read: impl FnOnce(&mut dyn ReadSeek) -> io::Result<T>,
+) -> io::Result<T> {
+    let pos_save = reader.stream_position()?;
+    reader.seek(SeekFrom::Current(distance as i64))?;
+    let value = read(reader)?;
+    reader.seek(SeekFrom::Start(pos_save))?;
+    Ok(value)
+}
@@ -0,0 +1,56 @@
+//! Optional integrity checking for serialized `PlayerColorParam` buffers.
+//!
+//! CRC32 is the fast default; MD5 is available as a slower, stronger opt-in, mirroring
+//! the `--md5` switch nod-rs exposes for ROM verification.
+
+use crate::{from_binary_file, to_binary_data, PlayerColorParam};
+use std::io;
+use std::path::Path;
+
+/// Which digest to compute over a serialized buffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChecksumAlgorithm {
+    /// Fast, and the right choice unless you have a reason to want MD5.
+    Crc32,
+    /// Slower but stronger; opt in when you need it.
+    Md5,
+}
+
+/// A digest produced under a [`ChecksumAlgorithm`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Checksum {
+    Crc32(u32),
+    Md5([u8; 16]),
+}
+
+impl Checksum {
+    pub fn of(data: &[u8], algorithm: ChecksumAlgorithm) -> Self {
+        match algorithm {
+            ChecksumAlgorithm::Crc32 => Checksum::Crc32(crc32fast::hash(data)),
+            ChecksumAlgorithm::Md5 => Checksum::Md5(md5::compute(data).0),
+        }
+    }
+}
+
+/// Serializes `param` to its binary form and computes a [`Checksum`] over the result.
+pub fn to_binary_data_with_checksum(
+    param: &PlayerColorParam,
+    algorithm: ChecksumAlgorithm,
+) -> io::Result<(Vec<u8>, Checksum)> {
+    let bytes = to_binary_data(param)?;
+    let checksum = Checksum::of(&bytes, algorithm);
+    Ok((bytes, checksum))
+}
+
+/// Loads the `PlayerColorParam` at `path`, re-serializes it, and reports whether the
+/// re-serialized bytes match `expected`. Lets mod tools catch corrupted or
+/// partially-edited palette files before they ship into the game.
+pub fn verify<P: AsRef<Path>>(path: P, expected: &Checksum) -> io::Result<bool> {
+    let algorithm = match expected {
+        Checksum::Crc32(_) => ChecksumAlgorithm::Crc32,
+        Checksum::Md5(_) => ChecksumAlgorithm::Md5,
+    };
+    let param = from_binary_file(path)?;
+    let (_, actual) = to_binary_data_with_checksum(&param, algorithm)?;
+    Ok(actual == *expected)
+}
@@ -1,84 +1,189 @@
-use player_color_param::{PlayerColorParam, EntryKey, RGB};
+mod codec;
+mod integrity;
+mod types;
+
+pub use codec::{FromReader, ToWriter};
+pub use integrity::{to_binary_data_with_checksum, verify, Checksum, ChecksumAlgorithm};
+pub use types::{PlayerColorParam, EntryKey, RGB};
+
+use codec::{at_relative_offset, read_cstring, read_relative_offset, write_relative_offset, ReadSeek};
 use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
-use std::io::{Read, BufReader, Write, Seek, SeekFrom};
-use std::mem::size_of;
-use indexmap::IndexMap;
+use std::io::{Read, BufReader, Write, Seek, Cursor};
 use std::collections::HashMap;
+use indexmap::IndexMap;
 
-pub fn from_binary_file<P: AsRef<std::path::Path>>(path: P) -> std::io::Result<PlayerColorParam> {
-    let file = std::fs::File::open(path)?;
-    from_binary_data(&mut BufReader::new(file))
+/// One decoded entry record, before its `character_id`/`costume_index` pair is used to
+/// assign an `alt_index` and fold it into a [`PlayerColorParam`]'s entry map.
+struct DecodedEntry {
+    character_id: String,
+    costume_index: u8,
+    color: RGB,
 }
 
-pub fn from_binary_data<R: Read + Seek>(reader: &mut R) -> std::io::Result<PlayerColorParam> {
-    const EXPECTED_VERSION: u32 = 1000;
-    let version = reader.read_u32::<LittleEndian>()?;
-    if version != EXPECTED_VERSION {
-        return Err(std::io::Error::new(
-                std::io::ErrorKind::InvalidData,
-                format!("Unsupported version. Expected version {} but got {}.", EXPECTED_VERSION, version)
-        ));
+/// A table version's entry layout: the fixed record size plus the read/write functions
+/// for it. Adding support for a new version means adding one arm to [`entry_codec`],
+/// not a matching arm in every function that touches an entry's binary layout.
+struct EntryCodec {
+    /// Size in bytes of the fixed-layout entry record (everything but the
+    /// NUL-terminated `character_id` it points into the string pool).
+    size: usize,
+    read: fn(&mut dyn ReadSeek) -> std::io::Result<DecodedEntry>,
+    write: fn(&mut dyn Write, usize, u8, &RGB) -> std::io::Result<()>,
+}
+
+fn entry_codec(version: u32) -> std::io::Result<EntryCodec> {
+    match version {
+        1000 => Ok(EntryCodec {
+            // character_id_offset(8) + costume_index(4) + red/green/blue(4*3)
+            size: 24,
+            read: read_entry_v1000,
+            write: write_entry_v1000,
+        }),
+        _ => Err(unsupported_version_error(version)),
     }
+}
 
-    let entry_count = reader.read_u32::<LittleEndian>()?;
-    // May be used for inserting additional data ignored by the parser.
-    let data_offset = reader.read_u64::<LittleEndian>()? - size_of::<u64>() as u64;
-
-    reader.seek(SeekFrom::Current(data_offset as i64))?;
-
-    let mut entries = IndexMap::<EntryKey, RGB>::new();
-    let mut alt_tracker = HashMap::<(String, u8), u8>::new();
-    for _ in 0..entry_count {
-        let character_id_offset = reader.read_u64::<LittleEndian>()? - size_of::<u64>() as u64;
-        let pos_save = reader.stream_position()?;
-        reader.seek(SeekFrom::Current(character_id_offset as i64))?;
-        let character_id = read_cstring(reader)?;
-        reader.seek(SeekFrom::Start(pos_save))?;
-
-        let costume_index = reader.read_u32::<LittleEndian>()? as u8;
-
-        let alt_tracker_key = (character_id.clone(), costume_index);
-        let alt_index = {
-            let count = alt_tracker.entry(alt_tracker_key).or_insert(0);
-            let current = *count;
-            *count += 1;
-            current
-        };
+fn unsupported_version_error(version: u32) -> std::io::Error {
+    std::io::Error::new(
+        std::io::ErrorKind::InvalidData,
+        format!("Unsupported version: {}", version),
+    )
+}
 
-        let red = reader.read_u32::<LittleEndian>()? as u8;
-        let green = reader.read_u32::<LittleEndian>()? as u8;
-        let blue = reader.read_u32::<LittleEndian>()? as u8;
+/// Reads a single version-1000 entry record, resolving its `character_id` through
+/// the relative pointer scheme shared by every version.
+fn read_entry_v1000(reader: &mut dyn ReadSeek) -> std::io::Result<DecodedEntry> {
+    let character_id_offset = read_relative_offset(reader)?;
+    let character_id = at_relative_offset(reader, character_id_offset, |r| read_cstring(r))?;
 
-        entries.insert(
-            EntryKey {
-                character_id,
-                costume_index,
-                alt_index,
-            },
-            RGB {
-                red,
-                green,
-                blue,
-            }
-        );
-    }
+    let reader: &mut dyn Read = reader;
+    let costume_index = reader.read_u32::<LittleEndian>()? as u8;
 
-    Ok(PlayerColorParam {
-        entries,
-    })
+    let red = reader.read_u32::<LittleEndian>()? as u8;
+    let green = reader.read_u32::<LittleEndian>()? as u8;
+    let blue = reader.read_u32::<LittleEndian>()? as u8;
+
+    Ok(DecodedEntry { character_id, costume_index, color: RGB { red, green, blue } })
 }
 
-fn read_cstring<R: Read>(reader: &mut R) -> std::io::Result<String> {
-    let mut bytes = Vec::new();
-    loop {
-        let byte = reader.read_u8()?;
-        if byte == 0 {
-            break;
+/// Writes a single version-1000 entry record. `character_id_offset` must already be
+/// resolved to this version's relative-pointer convention.
+fn write_entry_v1000(
+    writer: &mut dyn Write,
+    character_id_offset: usize,
+    costume_index: u8,
+    color: &RGB,
+) -> std::io::Result<()> {
+    writer.write_u64::<LittleEndian>(character_id_offset as u64)?;
+    writer.write_u32::<LittleEndian>(costume_index as u32)?;
+    writer.write_u32::<LittleEndian>(color.red as u32)?;
+    writer.write_u32::<LittleEndian>(color.green as u32)?;
+    writer.write_u32::<LittleEndian>(color.blue as u32)?;
+    Ok(())
+}
+
+impl FromReader for PlayerColorParam {
+    fn from_reader<R: Read + Seek>(reader: &mut R) -> std::io::Result<Self> {
+        let version = reader.read_u32::<LittleEndian>()?;
+        // Fail fast on a version with no registered entry layout, even if entry_count is 0.
+        let codec = entry_codec(version)?;
+
+        let entry_count = reader.read_u32::<LittleEndian>()?;
+        // Extra data some files carry between the header and the entry table. Its contents
+        // are unknown, so it's kept verbatim to let `to_writer` reproduce it byte-for-byte.
+        let data_offset = read_relative_offset(reader)?;
+        let mut reserved = vec![0u8; data_offset as usize];
+        reader.read_exact(&mut reserved)?;
+
+        let mut entries = IndexMap::<EntryKey, RGB>::new();
+        let mut alt_tracker = HashMap::<(String, u8), u8>::new();
+        for _ in 0..entry_count {
+            let DecodedEntry { character_id, costume_index, color } = (codec.read)(reader)?;
+
+            let alt_tracker_key = (character_id.clone(), costume_index);
+            let alt_index = {
+                let count = alt_tracker.entry(alt_tracker_key).or_insert(0);
+                let current = *count;
+                *count += 1;
+                current
+            };
+
+            entries.insert(
+                EntryKey {
+                    character_id,
+                    costume_index,
+                    alt_index,
+                },
+                color,
+            );
         }
-        bytes.push(byte);
+
+        Ok(PlayerColorParam {
+            version,
+            entries,
+            reserved,
+        })
+    }
+}
+
+impl ToWriter for PlayerColorParam {
+    /// Entries sharing a `character_id` are patched to point at one shared pool entry, so
+    /// a file re-encoded from a source with duplicate `character_id`s is only guaranteed
+    /// semantically equivalent to the original, not byte-for-byte identical.
+    fn to_writer<W: Write + Seek>(&self, writer: &mut W) -> std::io::Result<()> {
+        let codec = entry_codec(self.version)?;
+        let fixed_entry_size = codec.size;
+        let entry_count: usize = self.entries.len();
+
+        let entries = {
+            let mut entries = self.entries.clone();
+            entries.sort_keys();
+            entries
+        };
+
+        // Build a deduplicated, NUL-terminated, 8-byte-aligned string pool up front so each
+        // entry's character_id_offset can be patched from the position its string landed at.
+        // Deduplication means a re-encoded file is only guaranteed byte-identical to its
+        // source when no two entries share a character_id; source files that legitimately
+        // repeat one are still decoded correctly, just canonicalized to one shared pool entry.
+        let mut pool = Vec::new();
+        let mut pool_offsets = HashMap::<&str, usize>::new();
+        for key in entries.keys() {
+            pool_offsets.entry(key.character_id.as_str()).or_insert_with(|| {
+                let offset = pool.len();
+                pool.extend_from_slice(key.character_id.as_bytes());
+                pool.push(0);
+                while pool.len() % 8 != 0 {
+                    pool.push(0);
+                }
+                offset
+            });
+        }
+
+        writer.write_u32::<LittleEndian>(self.version)?;
+        writer.write_u32::<LittleEndian>(entry_count as u32)?;
+        write_relative_offset(writer, self.reserved.len() as u64)?;
+        writer.write_all(&self.reserved)?;
+
+        for (i, (key, value)) in entries.iter().enumerate() {
+            let pool_offset = pool_offsets[key.character_id.as_str()];
+            let character_id_offset = fixed_entry_size * (entry_count - i) + pool_offset;
+            (codec.write)(writer, character_id_offset, key.costume_index, value)?;
+        }
+
+        writer.write_all(&pool)?;
+
+        Ok(())
     }
-    String::from_utf8(bytes)
-        .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))
+}
+
+pub fn from_binary_file<P: AsRef<std::path::Path>>(path: P) -> std::io::Result<PlayerColorParam> {
+    let file = std::fs::File::open(path)?;
+    from_binary_data(&mut BufReader::new(file))
+}
+
+pub fn from_binary_data<R: Read + Seek>(reader: &mut R) -> std::io::Result<PlayerColorParam> {
+    PlayerColorParam::from_reader(reader)
 }
 
 pub fn to_binary_file<P: AsRef<std::path::Path>>(data: &PlayerColorParam, path: P) -> std::io::Result<()> {
@@ -88,47 +193,45 @@ pub fn to_binary_file<P: AsRef<std::path::Path>>(data: &PlayerColorParam, path:
 }
 
 pub fn to_binary_data(param: &PlayerColorParam) -> std::io::Result<Vec<u8>> {
-    const HEADER_SIZE: usize = 16;
-    const STRING_LENGTH: usize = 8; // Assumed to be constant;
-    const ENTRY_SIZE: usize = 24 + STRING_LENGTH;
-    let entry_count: usize = param.entries.len();
-    let size = HEADER_SIZE + entry_count * ENTRY_SIZE;
-    let mut buffer = Vec::with_capacity(size);
-
-    const VERSION: u32 = 1000;
-    buffer.write_u32::<LittleEndian>(VERSION)?;
-    buffer.write_u32::<LittleEndian>(entry_count as u32)?;
-    buffer.write_u64::<LittleEndian>(size_of::<u64>() as u64)?;
-
-    let entries = {
-        let mut entries = param.entries.clone();
-        entries.sort_keys();
-        entries
-    };
-
-    let mut i: usize = 0;
-    for (key, value) in &entries {
-        let character_id_offset = (ENTRY_SIZE - STRING_LENGTH) * (entry_count - i) + STRING_LENGTH * i;
-        buffer.write_u64::<LittleEndian>(character_id_offset as u64)?;
-
-        buffer.write_u32::<LittleEndian>(key.costume_index as u32)?;
-
-        buffer.write_u32::<LittleEndian>(value.red as u32)?;
-        buffer.write_u32::<LittleEndian>(value.green as u32)?;
-        buffer.write_u32::<LittleEndian>(value.blue as u32)?;
-
-        i += 1;
-    }
+    let mut buffer = Vec::new();
+    param.to_writer(&mut Cursor::new(&mut buffer))?;
+    Ok(buffer)
+}
 
-    for (key, _) in &entries {
-        buffer.write_all(key.character_id.as_bytes())?;
-        // Assume that string length is always 6,
-        // and use 8-byte alignment.
-        buffer.write_u8(0)?;
-        buffer.write_u8(0)?;
-    }
+pub fn from_json_file<P: AsRef<std::path::Path>>(path: P) -> std::io::Result<PlayerColorParam> {
+    from_json(&std::fs::read_to_string(path)?)
+}
 
-    Ok(buffer)
+pub fn from_json(json: &str) -> std::io::Result<PlayerColorParam> {
+    serde_json::from_str(json)
+        .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))
+}
+
+pub fn to_json_file<P: AsRef<std::path::Path>>(data: &PlayerColorParam, path: P) -> std::io::Result<()> {
+    std::fs::write(path, to_json(data)?)
+}
+
+pub fn to_json(param: &PlayerColorParam) -> std::io::Result<String> {
+    serde_json::to_string_pretty(param)
+        .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))
+}
+
+pub fn from_toml_file<P: AsRef<std::path::Path>>(path: P) -> std::io::Result<PlayerColorParam> {
+    from_toml(&std::fs::read_to_string(path)?)
+}
+
+pub fn from_toml(toml: &str) -> std::io::Result<PlayerColorParam> {
+    toml::from_str(toml)
+        .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))
+}
+
+pub fn to_toml_file<P: AsRef<std::path::Path>>(data: &PlayerColorParam, path: P) -> std::io::Result<()> {
+    std::fs::write(path, to_toml(data)?)
+}
+
+pub fn to_toml(param: &PlayerColorParam) -> std::io::Result<String> {
+    toml::to_string_pretty(param)
+        .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))
 }
 
 #[cfg(test)]
@@ -201,6 +304,13 @@ mod tests {
         };
         assert!(result.entries.contains_key(&key));
         assert_eq!(result.entries[&key], RGB { red: 143, green: 246, blue: 72 });
+        assert_eq!(result.version, 1000);
+    }
+
+    #[test]
+    fn to_binary_rejects_unsupported_version() {
+        let param = PlayerColorParam { version: 9999, entries: IndexMap::new(), reserved: Vec::new() };
+        assert!(to_binary_data(&param).is_err());
     }
 
     #[test]
@@ -255,9 +365,11 @@ mod tests {
             }
         );
 
-        let param = PlayerColorParam { entries };
+        let param = PlayerColorParam { version: 1000, entries, reserved: Vec::new() };
         let binary_data = to_binary_data(&param).unwrap();
 
+        // "1jnt01" is shared by three entries and appears once in the pool; "2jsp01"
+        // follows it, so character_id_offset values are patched from just two strings.
         let expected_output = vec![
             0xE8, 0x03, 0x00, 0x00, // version: u32 = 1000
             0x04, 0x00, 0x00, 0x00, // entry_count: u32 = 4
@@ -265,28 +377,158 @@ mod tests {
             0x60, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // character_id_ptr: u64 = 96
             0x02, 0x00, 0x00, 0x00, // costume_index: u32 = 2
             0xFF, 0x00, 0x00, 0x00, // red: u32 = 255
-            0xFF, 0x00, 0x00, 0x00, // blue: u32 = 255
             0xFF, 0x00, 0x00, 0x00, // green: u32 = 255
-            0x50, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // character_id_ptr: u64 = 80
+            0xFF, 0x00, 0x00, 0x00, // blue: u32 = 255
+            0x48, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // character_id_ptr: u64 = 72
             0x03, 0x00, 0x00, 0x00, // costume_index: u32 = 3
             0x40, 0x00, 0x00, 0x00, // red: u32 = 64
-            0x52, 0x00, 0x00, 0x00, // blue: u32 = 82
-            0xC5, 0x00, 0x00, 0x00, // green: u32 = 197
-            0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // character_id_ptr: u64 = 64
+            0x52, 0x00, 0x00, 0x00, // green: u32 = 82
+            0xC5, 0x00, 0x00, 0x00, // blue: u32 = 197
+            0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // character_id_ptr: u64 = 48
             0x03, 0x00, 0x00, 0x00, // costume_index: u32 = 3
             0x8F, 0x00, 0x00, 0x00, // red: u32 = 143
-            0xF6, 0x00, 0x00, 0x00, // blue: u32 = 246
-            0x48, 0x00, 0x00, 0x00, // green: u32 = 72
-            0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // character_id_ptr: u64 = 48
+            0xF6, 0x00, 0x00, 0x00, // green: u32 = 246
+            0x48, 0x00, 0x00, 0x00, // blue: u32 = 72
+            0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // character_id_ptr: u64 = 32
             0x00, 0x00, 0x00, 0x00, // costume_index: u32 = 0
             0x00, 0x00, 0x00, 0x00, // red: u32 = 0
-            0x00, 0x00, 0x00, 0x00, // blue: u32 = 0
             0x00, 0x00, 0x00, 0x00, // green: u32 = 0
-            0x31, 0x6A, 0x6E, 0x74, 0x30, 0x31, 0x00, 0x00, // character_id = "1jnt01"
-            0x31, 0x6A, 0x6E, 0x74, 0x30, 0x31, 0x00, 0x00, // character_id = "1jnt01"
+            0x00, 0x00, 0x00, 0x00, // blue: u32 = 0
             0x31, 0x6A, 0x6E, 0x74, 0x30, 0x31, 0x00, 0x00, // character_id = "1jnt01"
             0x32, 0x6A, 0x73, 0x70, 0x30, 0x31, 0x00, 0x00, // character_id = "2jsp01"
         ];
         assert_eq!(binary_data, expected_output);
     }
+
+    #[test]
+    fn to_binary_dedupes_and_supports_variable_length_ids() {
+        let mut entries = IndexMap::new();
+        entries.insert(
+            EntryKey { character_id: "ab".to_string(), costume_index: 0, alt_index: 0 },
+            RGB { red: 1, green: 2, blue: 3 },
+        );
+        entries.insert(
+            EntryKey { character_id: "ab".to_string(), costume_index: 1, alt_index: 0 },
+            RGB { red: 4, green: 5, blue: 6 },
+        );
+        entries.insert(
+            EntryKey { character_id: "a_very_long_character_id".to_string(), costume_index: 0, alt_index: 0 },
+            RGB { red: 7, green: 8, blue: 9 },
+        );
+
+        let param = PlayerColorParam { version: 1000, entries, reserved: Vec::new() };
+        let binary_data = to_binary_data(&param).unwrap();
+
+        // Only two distinct strings should appear in the pool, despite "ab" being used twice.
+        let pool_occurrences = binary_data.windows(2).filter(|window| *window == b"ab").count();
+        assert_eq!(pool_occurrences, 1);
+
+        let result = from_binary_data(&mut Cursor::new(binary_data)).unwrap();
+        assert_eq!(result.entries, param.entries);
+    }
+
+    #[test]
+    fn round_trip_preserves_reserved_region() {
+        // Uses two distinct character_ids rather than a repeated one: to_writer
+        // deduplicates the string pool, so a file with a duplicated character_id
+        // isn't guaranteed to re-encode byte-for-byte (see to_writer's doc comment).
+        let data = vec![
+            0xE8, 0x03, 0x00, 0x00, // version: u32 = 1000
+            0x02, 0x00, 0x00, 0x00, // entry_count: u32 = 2
+            0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // data_offset: u64 = 16
+            0x68, 0x65, 0x6C, 0x6C, 0x6F, 0x00, 0x00, 0x00, // reserved: "hello"
+            0x30, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // character_id_ptr: u64 = 48
+            0x03, 0x00, 0x00, 0x00, // costume_index: u32 = 3
+            0x40, 0x00, 0x00, 0x00, // red: u32 = 64
+            0x52, 0x00, 0x00, 0x00, // green: u32 = 82
+            0xC5, 0x00, 0x00, 0x00, // blue: u32 = 197
+            0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // character_id_ptr: u64 = 32
+            0x03, 0x00, 0x00, 0x00, // costume_index: u32 = 3
+            0x8F, 0x00, 0x00, 0x00, // red: u32 = 143
+            0xF6, 0x00, 0x00, 0x00, // green: u32 = 246
+            0x48, 0x00, 0x00, 0x00, // blue: u32 = 72
+            0x31, 0x6A, 0x6E, 0x74, 0x30, 0x31, 0x00, 0x00, // character_id = "1jnt01"
+            0x32, 0x6A, 0x73, 0x70, 0x30, 0x31, 0x00, 0x00, // character_id = "2jsp01"
+        ];
+
+        let result = from_binary_data(&mut Cursor::new(data.clone())).unwrap();
+        assert_eq!(result.reserved, b"hello\0\0\0");
+
+        let re_encoded = to_binary_data(&result).unwrap();
+        assert_eq!(re_encoded, data);
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let mut entries = IndexMap::new();
+        entries.insert(
+            EntryKey {
+                character_id: "1jnt01".to_string(),
+                costume_index: 3,
+                alt_index: 0,
+            },
+            RGB { red: 64, green: 82, blue: 197 },
+        );
+        entries.insert(
+            EntryKey {
+                character_id: "1jnt01".to_string(),
+                costume_index: 3,
+                alt_index: 1,
+            },
+            RGB { red: 143, green: 246, blue: 72 },
+        );
+
+        let param = PlayerColorParam { version: 1000, entries, reserved: Vec::new() };
+        let json = to_json(&param).unwrap();
+        let result = from_json(&json).unwrap();
+        assert_eq!(result, param);
+    }
+
+    #[test]
+    fn toml_round_trip() {
+        let mut entries = IndexMap::new();
+        entries.insert(
+            EntryKey {
+                character_id: "2jsp01".to_string(),
+                costume_index: 0,
+                alt_index: 0,
+            },
+            RGB { red: 0, green: 0, blue: 0 },
+        );
+
+        let param = PlayerColorParam { version: 1000, entries, reserved: Vec::new() };
+        let toml = to_toml(&param).unwrap();
+        let result = from_toml(&toml).unwrap();
+        assert_eq!(result, param);
+    }
+
+    #[test]
+    fn verify_detects_matching_and_edited_files() {
+        let mut entries = IndexMap::new();
+        entries.insert(
+            EntryKey { character_id: "1jnt01".to_string(), costume_index: 3, alt_index: 0 },
+            RGB { red: 64, green: 82, blue: 197 },
+        );
+        let param = PlayerColorParam { version: 1000, entries, reserved: Vec::new() };
+
+        let (bytes, crc32_checksum) = to_binary_data_with_checksum(&param, ChecksumAlgorithm::Crc32).unwrap();
+        let (_, md5_checksum) = to_binary_data_with_checksum(&param, ChecksumAlgorithm::Md5).unwrap();
+
+        let path = std::env::temp_dir().join("player_color_param_verify_test.bin");
+        std::fs::write(&path, &bytes).unwrap();
+
+        assert!(verify(&path, &crc32_checksum).unwrap());
+        assert!(verify(&path, &md5_checksum).unwrap());
+
+        let mut edited_entries = IndexMap::new();
+        edited_entries.insert(
+            EntryKey { character_id: "2jsp01".to_string(), costume_index: 0, alt_index: 0 },
+            RGB { red: 1, green: 2, blue: 3 },
+        );
+        let edited = PlayerColorParam { version: 1000, entries: edited_entries, reserved: Vec::new() };
+        to_binary_file(&edited, &path).unwrap();
+        assert!(!verify(&path, &crc32_checksum).unwrap());
+
+        std::fs::remove_file(&path).unwrap();
+    }
 }
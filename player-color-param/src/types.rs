@@ -0,0 +1,136 @@
+//! In-memory data model for `PlayerColorParam`, independent of any particular encoding.
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// The table version read by the only entry layout this crate currently understands.
+pub const DEFAULT_VERSION: u32 = 1000;
+
+/// A parsed `PlayerColorParam` table: the per-character, per-costume color overrides.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlayerColorParam {
+    /// The format version the entry table was read as (and will be written back as).
+    pub version: u32,
+    pub entries: IndexMap<EntryKey, RGB>,
+    /// Bytes that sat between the header and the entry table in the source file,
+    /// kept verbatim so `to_binary_data` can reproduce the original byte-for-byte.
+    pub reserved: Vec<u8>,
+}
+
+impl Default for PlayerColorParam {
+    fn default() -> Self {
+        PlayerColorParam {
+            version: DEFAULT_VERSION,
+            entries: IndexMap::new(),
+            reserved: Vec::new(),
+        }
+    }
+}
+
+/// Identifies a single color entry: a character's costume, and which "alt" slot
+/// this is for that costume (multiple entries can share a character/costume pair).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct EntryKey {
+    pub character_id: String,
+    pub costume_index: u8,
+    pub alt_index: u8,
+}
+
+/// An 8-bit-per-channel color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RGB {
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8,
+}
+
+impl Serialize for RGB {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("#{:02X}{:02X}{:02X}", self.red, self.green, self.blue))
+    }
+}
+
+impl<'de> Deserialize<'de> for RGB {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let hex = String::deserialize(deserializer)?;
+        let hex = hex.strip_prefix('#').unwrap_or(&hex);
+        // Checked up front so the byte-range slicing below can't land on a multi-byte
+        // UTF-8 character's interior and panic; `hex.len() != 6` alone isn't enough.
+        if hex.len() != 6 || !hex.is_ascii() {
+            return Err(serde::de::Error::custom(format!(
+                "expected a \"#RRGGBB\" color string, got \"{}\"", hex
+            )));
+        }
+        let channel = |range| {
+            u8::from_str_radix(&hex[range], 16)
+                .map_err(|err| serde::de::Error::custom(format!("invalid color channel: {}", err)))
+        };
+        Ok(RGB {
+            red: channel(0..2)?,
+            green: channel(2..4)?,
+            blue: channel(4..6)?,
+        })
+    }
+}
+
+/// Flattened, serde-friendly representation of a single `(EntryKey, RGB)` pair,
+/// so the `alt_index` collision tracking survives a text round trip.
+#[derive(Serialize, Deserialize)]
+struct ColorEntry {
+    character_id: String,
+    costume_index: u8,
+    alt_index: u8,
+    color: RGB,
+}
+
+/// Serde-friendly representation of `PlayerColorParam`. Entries are kept under a
+/// named field (rather than serialized as a bare list) so the format also works
+/// as a TOML document, which requires a table at the document root.
+#[derive(Serialize, Deserialize)]
+struct PlayerColorParamRepr {
+    #[serde(default = "default_version")]
+    version: u32,
+    entries: Vec<ColorEntry>,
+}
+
+fn default_version() -> u32 {
+    DEFAULT_VERSION
+}
+
+impl Serialize for PlayerColorParam {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let repr = PlayerColorParamRepr {
+            version: self.version,
+            entries: self.entries.iter()
+                .map(|(key, color)| ColorEntry {
+                    character_id: key.character_id.clone(),
+                    costume_index: key.costume_index,
+                    alt_index: key.alt_index,
+                    color: *color,
+                })
+                .collect(),
+        };
+        repr.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for PlayerColorParam {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = PlayerColorParamRepr::deserialize(deserializer)?;
+        Ok(PlayerColorParam {
+            version: repr.version,
+            entries: repr.entries.into_iter()
+                .map(|entry| (
+                    EntryKey {
+                        character_id: entry.character_id,
+                        costume_index: entry.costume_index,
+                        alt_index: entry.alt_index,
+                    },
+                    entry.color,
+                ))
+                .collect(),
+            // Not part of the human-editable text format; lost on a binary -> text -> binary trip.
+            reserved: Vec::new(),
+        })
+    }
+}